@@ -0,0 +1,4 @@
+//! Functionality that is only available on x86_64.
+
+pub mod segmentation;
+pub mod task;
@@ -0,0 +1,45 @@
+//! Segmentation helpers that only apply to 64-bit mode.
+
+use shared::msr::{rdmsr, wrmsr, IA32_GS_BASE, IA32_KERNEL_GS_BASE};
+use shared::segmentation::SegmentSelector;
+
+/// Loads the task register with a selector pointing at a TSS descriptor,
+/// making that TSS the current one.
+pub unsafe fn load_tss(sel: SegmentSelector) {
+    asm!("ltr %ax" :: "{ax}" (sel) : "memory");
+}
+
+/// Exchanges the value of the `IA32_GS_BASE` and `IA32_KERNEL_GS_BASE`
+/// MSRs.
+///
+/// `load_gs` only reloads the GS selector in 64-bit mode and leaves the
+/// 64-bit base untouched, so kernel-entry code that wants a per-CPU GS
+/// base must keep it in `IA32_KERNEL_GS_BASE` and swap it in with this
+/// instruction instead. The two MSRs must always be used as a pair: one
+/// `swapgs` on entry to install the kernel's base, and a second one
+/// before returning to user mode to restore the user base, otherwise the
+/// two stay swapped.
+pub unsafe fn swapgs() {
+    asm!("swapgs" ::: "memory" : "volatile");
+}
+
+/// Reads the GS base address currently loaded from `IA32_GS_BASE`.
+pub fn read_gs_base() -> u64 {
+    unsafe { rdmsr(IA32_GS_BASE) }
+}
+
+/// Writes `base` to the `IA32_GS_BASE` MSR.
+pub unsafe fn write_gs_base(base: u64) {
+    wrmsr(IA32_GS_BASE, base);
+}
+
+/// Reads the base address `swapgs` will install on its next execution,
+/// from `IA32_KERNEL_GS_BASE`.
+pub fn read_kernel_gs_base() -> u64 {
+    unsafe { rdmsr(IA32_KERNEL_GS_BASE) }
+}
+
+/// Writes `base` to the `IA32_KERNEL_GS_BASE` MSR.
+pub unsafe fn write_kernel_gs_base(base: u64) {
+    wrmsr(IA32_KERNEL_GS_BASE, base);
+}
@@ -0,0 +1,61 @@
+//! The 64-bit Task State Segment (TSS).
+
+use core::mem;
+
+use shared::descriptor::{Descriptor, PRESENT};
+
+/// In 64-bit mode the TSS no longer holds per-segment stacks; instead it
+/// carries the stacks used on privilege-level changes and the Interrupt
+/// Stack Table entries referenced by IDT gates, plus the I/O permission
+/// bitmap offset.
+#[repr(C, packed)]
+pub struct TaskStateSegment {
+    reserved_1: u32,
+    /// Stack pointers (RSP) loaded on a privilege-level change to ring
+    /// 0, 1 or 2.
+    pub privilege_stack_table: [u64; 3],
+    reserved_2: u64,
+    /// Interrupt Stack Table. An IDT gate whose IST field is `n` (1..=7)
+    /// uses entry `n - 1`; an IST field of 0 selects no entry.
+    pub interrupt_stack_table: [u64; 7],
+    reserved_3: u64,
+    reserved_4: u16,
+    /// Offset from the start of the TSS to the I/O permission bitmap.
+    pub iomap_base: u16,
+}
+
+impl TaskStateSegment {
+    /// Creates a new TSS with all stack table entries zeroed and no I/O
+    /// permission bitmap.
+    pub const fn new() -> TaskStateSegment {
+        TaskStateSegment {
+            reserved_1: 0,
+            privilege_stack_table: [0; 3],
+            reserved_2: 0,
+            interrupt_stack_table: [0; 7],
+            reserved_3: 0,
+            reserved_4: 0,
+            iomap_base: mem::size_of::<TaskStateSegment>() as u16,
+        }
+    }
+}
+
+impl Descriptor {
+    /// Builds the two-word system descriptor for a 64-bit TSS.
+    pub fn tss_segment(tss: &'static TaskStateSegment) -> Descriptor {
+        let ptr = tss as *const _ as u64;
+
+        let mut low = PRESENT.bits();
+        // Base address, bits 0..24 and 24..32.
+        low |= (ptr & 0xFF_FFFF) << 16;
+        low |= ((ptr >> 24) & 0xFF) << 56;
+        // Limit: size of the TSS, minus 1, in bytes.
+        low |= (mem::size_of::<TaskStateSegment>() - 1) as u64;
+        // Type: 0b1001, available 64-bit TSS.
+        low |= 0b1001 << 40;
+
+        let high = ptr >> 32;
+
+        Descriptor::SystemSegment(low, high)
+    }
+}
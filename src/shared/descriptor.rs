@@ -0,0 +1,105 @@
+//! x86 segment descriptors, as stored in a GDT or LDT.
+
+/// A 64-bit segment descriptor.
+///
+/// User segments (code/data) fit in a single `u64`. System segments (TSS,
+/// LDT, call gates, ...) occupy two consecutive `u64` slots in long mode,
+/// the second of which holds the high 32 bits of the base address.
+#[derive(Debug, Clone, Copy)]
+pub enum Descriptor {
+    UserSegment(u64),
+    SystemSegment(u64, u64),
+}
+
+bitflags! {
+    /// Flags making up the access byte and flags nibble of a segment
+    /// descriptor, as laid out in bits `40..56` of the descriptor, plus
+    /// masks for the limit and base fields in the remaining bits.
+    #[repr(C, packed)]
+    pub flags DescriptorFlags: u64 {
+        /// Set by the CPU when the segment is accessed; should be set
+        /// up-front for data segments to avoid a write fault on a
+        /// read-only GDT.
+        const ACCESSED      = 1 << 40,
+        /// For data segments, allows write access. For code segments,
+        /// allows read access.
+        const WRITABLE      = 1 << 41,
+        /// For code segments, marks the segment as conforming, which
+        /// affects the privilege checks on control transfers. For data
+        /// segments, marks the segment as "expand down".
+        const CONFORMING    = 1 << 42,
+        /// Must be set for code segments, unset for data segments.
+        const EXECUTABLE    = 1 << 43,
+        /// Must be set for user (code/data) segments, unset for system
+        /// segments (TSS, LDT, gates, ...).
+        const USER_SEGMENT  = 1 << 44,
+        /// Descriptor Privilege Level, bit 0.
+        const DPL_RING_1    = 1 << 45,
+        /// Descriptor Privilege Level, bit 1.
+        const DPL_RING_2    = 1 << 46,
+        /// Descriptor Privilege Level, both bits set (ring 3).
+        const DPL_RING_3    = 0b11 << 45,
+        /// Must be set for any valid segment; causes a segment-not-present
+        /// exception if unset.
+        const PRESENT       = 1 << 47,
+        /// Must be set for 64-bit code segments, unset otherwise.
+        const LONG_MODE     = 1 << 53,
+        /// 32-bit default operand/address size. Must be unset if
+        /// `LONG_MODE` is set.
+        const DEFAULT_SIZE  = 1 << 54,
+        /// Scales the limit field by 4096 instead of 1 byte.
+        const GRANULARITY   = 1 << 55,
+
+        /// Bits `0..16` of the limit field.
+        const LIMIT_0_15    = 0xFFFF,
+        /// Bits `16..20` of the limit field.
+        const LIMIT_16_19   = 0xF << 48,
+        /// Bits `0..24` of the base field.
+        const BASE_0_23     = 0xFF_FFFF << 16,
+        /// Bits `24..32` of the base field.
+        const BASE_24_31    = 0xFF << 56,
+    }
+}
+
+impl Descriptor {
+    /// A 64-bit ring-0 code segment descriptor.
+    pub fn kernel_code_segment() -> Descriptor {
+        let flags = USER_SEGMENT | PRESENT | EXECUTABLE | LONG_MODE;
+        Descriptor::UserSegment(flags.bits())
+    }
+
+    /// A ring-0 data segment descriptor.
+    pub fn kernel_data_segment() -> Descriptor {
+        let flags = USER_SEGMENT | PRESENT | WRITABLE;
+        Descriptor::UserSegment(flags.bits())
+    }
+
+    /// A 64-bit ring-3 code segment descriptor.
+    pub fn user_code_segment() -> Descriptor {
+        let flags = USER_SEGMENT | PRESENT | EXECUTABLE | LONG_MODE | DPL_RING_3;
+        Descriptor::UserSegment(flags.bits())
+    }
+
+    /// A ring-3 data segment descriptor.
+    pub fn user_data_segment() -> Descriptor {
+        let flags = USER_SEGMENT | PRESENT | WRITABLE | DPL_RING_3;
+        Descriptor::UserSegment(flags.bits())
+    }
+
+    /// Extracts the Descriptor Privilege Level encoded in this descriptor,
+    /// so callers building a GDT can match the RPL of the returned
+    /// selector to it.
+    pub const fn dpl(&self) -> ::shared::PrivilegeLevel {
+        let low = match *self {
+            Descriptor::UserSegment(value) => value,
+            Descriptor::SystemSegment(value, _) => value,
+        };
+
+        match (low >> 45) & 0b11 {
+            0 => ::shared::PrivilegeLevel::Ring0,
+            1 => ::shared::PrivilegeLevel::Ring1,
+            2 => ::shared::PrivilegeLevel::Ring2,
+            _ => ::shared::PrivilegeLevel::Ring3,
+        }
+    }
+}
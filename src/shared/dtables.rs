@@ -0,0 +1,20 @@
+//! Data structures and functions used by the `lgdt`/`lidt` family of
+//! instructions to load descriptor tables.
+
+/// A pointer to a descriptor table (GDT/IDT/LDT), in the format expected
+/// by `lgdt`/`lidt`/`sgdt`/`sidt`.
+///
+/// This does not own the table it points to; the table must outlive the
+/// pointer being loaded into the CPU.
+#[repr(C, packed)]
+pub struct DescriptorTablePointer<T> {
+    /// Size of the DT in bytes, minus 1.
+    pub limit: u16,
+    /// Pointer to the start of the DT.
+    pub base: *const T,
+}
+
+/// Load a GDT.
+pub unsafe fn lgdt(gdt: &DescriptorTablePointer<u64>) {
+    asm!("lgdt ($0)" :: "r" (gdt) : "memory");
+}
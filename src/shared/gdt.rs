@@ -0,0 +1,71 @@
+//! A type-safe builder for the Global Descriptor Table (GDT).
+
+use core::mem;
+
+use shared::descriptor::Descriptor;
+use shared::dtables::{lgdt, DescriptorTablePointer};
+use shared::segmentation::SegmentSelector;
+
+/// Number of `u64` slots in the backing array of a `GlobalDescriptorTable`.
+const GDT_ENTRIES: usize = 8;
+
+/// A 64-bit Global Descriptor Table.
+///
+/// Descriptors are appended with
+/// [`add_entry`](GlobalDescriptorTable::add_entry), which hands back a
+/// ready-to-use `SegmentSelector` for the newly added entry. Slot 0 is
+/// reserved for the (mandatory) null descriptor.
+#[repr(align(16))]
+pub struct GlobalDescriptorTable {
+    table: [u64; GDT_ENTRIES],
+    next_free: usize,
+}
+
+impl GlobalDescriptorTable {
+    /// Creates an empty GDT, containing only the null descriptor.
+    pub const fn new() -> GlobalDescriptorTable {
+        GlobalDescriptorTable {
+            table: [0; GDT_ENTRIES],
+            next_free: 1,
+        }
+    }
+
+    /// Appends `descriptor` to the table and returns a selector for it,
+    /// with the RPL set to the descriptor's DPL.
+    ///
+    /// System descriptors (TSS/LDT) occupy two slots in long mode; this is
+    /// handled transparently and the returned selector points at the
+    /// first of the two slots.
+    pub fn add_entry(&mut self, descriptor: Descriptor) -> SegmentSelector {
+        let index = match descriptor {
+            Descriptor::UserSegment(value) => self.push(value),
+            Descriptor::SystemSegment(value_low, value_high) => {
+                let index = self.push(value_low);
+                self.push(value_high);
+                index
+            }
+        };
+
+        SegmentSelector::new(index as u16, descriptor.dpl())
+    }
+
+    fn push(&mut self, value: u64) -> usize {
+        let index = self.next_free;
+        assert!(index < self.table.len(), "GDT has no more free entries");
+        self.table[index] = value;
+        self.next_free += 1;
+        index
+    }
+
+    /// Loads this GDT into the CPU via `lgdt`.
+    ///
+    /// The table must have `'static` lifetime, since the CPU keeps
+    /// referring to it (through the GDTR) after this function returns.
+    pub unsafe fn load(&'static self) {
+        let ptr = DescriptorTablePointer {
+            base: self.table.as_ptr(),
+            limit: (self.table.len() * mem::size_of::<u64>() - 1) as u16,
+        };
+        lgdt(&ptr);
+    }
+}
@@ -0,0 +1,31 @@
+//! Data structures and functions that are shared between the x86 and
+//! x86_64 architectures.
+
+pub mod descriptor;
+pub mod dtables;
+pub mod gdt;
+pub mod msr;
+pub mod segmentation;
+
+/// x86 privilege levels (rings), used in segment descriptors and
+/// selectors to express CPL/DPL/RPL.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PrivilegeLevel {
+    Ring0 = 0,
+    Ring1 = 1,
+    Ring2 = 2,
+    Ring3 = 3,
+}
+
+impl PrivilegeLevel {
+    /// Creates a `PrivilegeLevel` from a numeric value in the range `0..=3`.
+    pub fn from_u16(value: u16) -> PrivilegeLevel {
+        match value {
+            0 => PrivilegeLevel::Ring0,
+            1 => PrivilegeLevel::Ring1,
+            2 => PrivilegeLevel::Ring2,
+            3 => PrivilegeLevel::Ring3,
+            _ => panic!("invalid privilege level {}", value),
+        }
+    }
+}
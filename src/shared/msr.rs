@@ -0,0 +1,21 @@
+//! Access to x86 Model Specific Registers (MSRs).
+
+/// Holds the 64-bit base address currently loaded into the GS segment.
+pub const IA32_GS_BASE: u32 = 0xC0000101;
+/// Swapped into `IA32_GS_BASE` by the `swapgs` instruction; used to stash
+/// the kernel's per-CPU base while user GS is active.
+pub const IA32_KERNEL_GS_BASE: u32 = 0xC0000102;
+
+/// Read 64 bits from an MSR.
+pub unsafe fn rdmsr(msr: u32) -> u64 {
+    let (high, low): (u32, u32);
+    asm!("rdmsr" : "={eax}" (low), "={edx}" (high) : "{ecx}" (msr) : : "volatile");
+    ((high as u64) << 32) | (low as u64)
+}
+
+/// Write 64 bits to an MSR.
+pub unsafe fn wrmsr(msr: u32, value: u64) {
+    let low = value as u32;
+    let high = (value >> 32) as u32;
+    asm!("wrmsr" :: "{ecx}" (msr), "{eax}" (low), "{edx}" (high) : "memory" : "volatile");
+}
@@ -15,9 +15,9 @@ bitflags! {
         const RPL_3 = 0b11,
 
         /// Table Indicator (TI) 0 means GDT is used.
-        const TI_GDT = 0 << 3,
+        const TI_GDT = 0 << 2,
         /// Table Indicator (TI) 1 means LDT is used.
-        const TI_LDT = 1 << 3,
+        const TI_LDT = 1 << 2,
     }
 }
 
@@ -64,6 +64,32 @@ impl SegmentSelector {
     pub const fn from_raw(bits: u16) -> SegmentSelector {
         SegmentSelector { bits: bits }
     }
+
+    /// Returns the index of this selector into its descriptor table.
+    pub fn index(&self) -> u16 {
+        self.bits() >> 3
+    }
+
+    /// Returns the Requestor Privilege Level encoded in this selector.
+    pub fn rpl(&self) -> PrivilegeLevel {
+        PrivilegeLevel::from_u16(self.bits() & 0b11)
+    }
+
+    /// Returns which descriptor table this selector indexes into.
+    pub fn table(&self) -> TableIndicator {
+        if self.contains(TI_LDT) {
+            TableIndicator::LDT
+        } else {
+            TableIndicator::GDT
+        }
+    }
+}
+
+/// The descriptor table a `SegmentSelector` indexes into.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum TableIndicator {
+    GDT,
+    LDT,
 }
 
 impl fmt::Display for SegmentSelector {